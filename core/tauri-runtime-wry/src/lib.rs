@@ -1055,6 +1055,7 @@ unsafe impl Send for RawWindowHandle {}
 pub enum ApplicationMessage {
   Show,
   Hide,
+  IsHidden(Sender<bool>),
 }
 
 pub enum WindowMessage {
@@ -1146,6 +1147,7 @@ pub enum WebviewMessage {
   #[allow(dead_code)]
   WebviewEvent(WebviewEvent),
   Print,
+  RequestRedraw,
 }
 
 #[allow(dead_code)]
@@ -1403,6 +1405,13 @@ impl<T: UserEvent> Dispatch<T> for WryDispatcher<T> {
     )
   }
 
+  fn request_redraw(&self) -> Result<()> {
+    send_user_message(
+      &self.context,
+      Message::Webview(self.window_id, WebviewMessage::RequestRedraw),
+    )
+  }
+
   fn request_user_attention(&self, request_type: Option<UserAttentionType>) -> Result<()> {
     send_user_message(
       &self.context,
@@ -1928,6 +1937,16 @@ impl<T: UserEvent> RuntimeHandle<T> for WryHandle<T> {
     )
   }
 
+  #[cfg(target_os = "macos")]
+  fn is_hidden(&self) -> tauri_runtime::Result<bool> {
+    let (tx, rx) = channel();
+    getter!(
+      self,
+      rx,
+      Message::Application(ApplicationMessage::IsHidden(tx))
+    )
+  }
+
   #[cfg(target_os = "android")]
   fn find_class<'a>(
     &'a self,
@@ -2112,6 +2131,11 @@ impl<T: UserEvent> Runtime<T> for Wry<T> {
     self.event_loop.hide_application();
   }
 
+  #[cfg(target_os = "macos")]
+  fn is_hidden(&self) -> bool {
+    is_application_hidden()
+  }
+
   fn set_device_event_filter(&mut self, filter: DeviceEventFilter) {
     self
       .event_loop
@@ -2241,6 +2265,60 @@ struct UserMessageContext {
   system_tray_manager: SystemTrayManager,
 }
 
+#[allow(unused_variables)]
+fn request_webview_redraw(webview: &WebView) {
+  #[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+  ))]
+  {
+    use gtk::prelude::WidgetExt;
+    use wry::webview::WebviewExtUnix;
+    webview.webview().queue_draw();
+  }
+  #[cfg(target_os = "macos")]
+  {
+    use cocoa::base::{id, YES};
+    use objc::{msg_send, sel, sel_impl};
+    use wry::webview::WebviewExtMacOS;
+    unsafe {
+      let view: id = webview.webview();
+      let () = msg_send![view, setNeedsDisplay: YES];
+    }
+  }
+  #[cfg(windows)]
+  {
+    use windows::Win32::Graphics::Gdi::{RedrawWindow, HRGN, RDW_ALLCHILDREN, RDW_INVALIDATE};
+    // WebView2 renders in a child window, so the children must be invalidated as well
+    unsafe {
+      RedrawWindow(
+        HWND(webview.window().hwnd() as _),
+        None,
+        HRGN::default(),
+        RDW_INVALIDATE | RDW_ALLCHILDREN,
+      );
+    }
+  }
+}
+
+#[cfg(target_os = "macos")]
+fn is_application_hidden() -> bool {
+  use cocoa::{
+    appkit::NSApp,
+    base::{id, BOOL, YES},
+  };
+  use objc::{msg_send, sel, sel_impl};
+
+  unsafe {
+    let app: id = NSApp();
+    let hidden: BOOL = msg_send![app, isHidden];
+    hidden == YES
+  }
+}
+
 fn handle_user_message<T: UserEvent>(
   event_loop: &EventLoopWindowTarget<Message<T>>,
   message: Message<T>,
@@ -2263,6 +2341,7 @@ fn handle_user_message<T: UserEvent>(
       ApplicationMessage::Hide => {
         event_loop.hide_application();
       }
+      ApplicationMessage::IsHidden(tx) => tx.send(is_application_hidden()).unwrap(),
     },
     Message::Window(id, window_message) => {
       if let WindowMessage::UpdateMenuItem(item_id, update) = window_message {
@@ -2539,6 +2618,13 @@ fn handle_user_message<T: UserEvent>(
           let _ = webview.print();
         }
       }
+      WebviewMessage::RequestRedraw => {
+        if let Some(WindowHandle::Webview { inner: webview, .. }) =
+          windows.borrow().get(&id).and_then(|w| w.inner.as_ref())
+        {
+          request_webview_redraw(webview);
+        }
+      }
       WebviewMessage::WebviewEvent(_event) => { /* already handled */ }
     },
     Message::CreateWebview(window_id, handler) => match handler(event_loop, web_context) {
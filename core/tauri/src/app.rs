@@ -576,8 +576,12 @@ macro_rules! shared_app_impl {
       }
 
       /// Shows the application, but does not automatically focus it.
-      #[cfg(target_os = "macos")]
+      ///
+      /// ## Platform-specific
+      ///
+      /// - **Windows / Linux / iOS / Android:** Unsupported, this is a no-op.
       pub fn show(&self) -> crate::Result<()> {
+        #[cfg(target_os = "macos")]
         match self.runtime() {
           RuntimeOrDispatch::Runtime(r) => r.show(),
           RuntimeOrDispatch::RuntimeHandle(h) => h.show()?,
@@ -586,9 +590,13 @@ macro_rules! shared_app_impl {
         Ok(())
       }
 
-      /// Hides the application.
-      #[cfg(target_os = "macos")]
+      /// Hides the application, minimizing all of its windows into the Dock.
+      ///
+      /// ## Platform-specific
+      ///
+      /// - **Windows / Linux / iOS / Android:** Unsupported, this is a no-op.
       pub fn hide(&self) -> crate::Result<()> {
+        #[cfg(target_os = "macos")]
         match self.runtime() {
           RuntimeOrDispatch::Runtime(r) => r.hide(),
           RuntimeOrDispatch::RuntimeHandle(h) => h.hide()?,
@@ -596,6 +604,22 @@ macro_rules! shared_app_impl {
         }
         Ok(())
       }
+
+      /// Whether the application is hidden.
+      ///
+      /// ## Platform-specific
+      ///
+      /// - **Windows / Linux / iOS / Android:** Unsupported, always returns `false`.
+      pub fn is_hidden(&self) -> crate::Result<bool> {
+        #[cfg(target_os = "macos")]
+        return match self.runtime() {
+          RuntimeOrDispatch::Runtime(r) => Ok(r.is_hidden()),
+          RuntimeOrDispatch::RuntimeHandle(h) => h.is_hidden().map_err(Into::into),
+          _ => unreachable!(),
+        };
+        #[cfg(not(target_os = "macos"))]
+        Ok(false)
+      }
     }
   };
 }
@@ -1524,4 +1548,16 @@ mod tests {
       crate::test_utils::assert_sync::<super::AssetResolver<crate::Wry>>();
     }
   }
+
+  #[test]
+  fn app_show_hide() {
+    let app = crate::test::mock_app();
+    let handle = app.handle();
+
+    handle.hide().unwrap();
+    // hiding the application is only supported on macOS
+    assert_eq!(handle.is_hidden().unwrap(), cfg!(target_os = "macos"));
+    handle.show().unwrap();
+    assert!(!handle.is_hidden().unwrap());
+  }
 }
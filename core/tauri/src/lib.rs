@@ -199,7 +199,7 @@ pub use {
     config::{Config, WindowUrl},
     Env, PackageInfo, Theme,
   },
-  self::window::{Monitor, Window, WindowBuilder},
+  self::window::{Monitor, RepaintHandle, Window, WindowBuilder},
   scope::*,
 };
 
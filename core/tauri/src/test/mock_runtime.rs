@@ -35,7 +35,7 @@ use std::{
   collections::HashMap,
   fmt,
   sync::{
-    atomic::{AtomicBool, Ordering},
+    atomic::{AtomicBool, AtomicUsize, Ordering},
     mpsc::{channel, sync_channel, Receiver, SyncSender},
     Arc, Mutex,
   },
@@ -57,6 +57,7 @@ pub struct RuntimeContext {
   windows: Arc<RefCell<HashMap<WindowId, Window>>>,
   shortcuts: Arc<Mutex<ShortcutMap>>,
   run_tx: SyncSender<Message>,
+  is_hidden: Arc<AtomicBool>,
 }
 
 // SAFETY: we ensure this type is only used on the main thread.
@@ -117,6 +118,7 @@ impl<T: UserEvent> RuntimeHandle<T> for MockRuntimeHandle {
         id,
         context: self.context.clone(),
         last_evaluated_script: Default::default(),
+        redraw_count: Default::default(),
         url: Arc::new(Mutex::new(pending.url)),
       },
       menu_ids: Default::default(),
@@ -157,15 +159,22 @@ impl<T: UserEvent> RuntimeHandle<T> for MockRuntimeHandle {
   /// Shows the application, but does not automatically focus it.
   #[cfg(target_os = "macos")]
   fn show(&self) -> Result<()> {
+    self.context.is_hidden.store(false, Ordering::Relaxed);
     Ok(())
   }
 
   /// Hides the application.
   #[cfg(target_os = "macos")]
   fn hide(&self) -> Result<()> {
+    self.context.is_hidden.store(true, Ordering::Relaxed);
     Ok(())
   }
 
+  #[cfg(target_os = "macos")]
+  fn is_hidden(&self) -> Result<bool> {
+    Ok(self.context.is_hidden.load(Ordering::Relaxed))
+  }
+
   #[cfg(target_os = "android")]
   fn find_class<'a>(
     &'a self,
@@ -193,12 +202,17 @@ pub struct MockDispatcher {
   context: RuntimeContext,
   url: Arc<Mutex<String>>,
   last_evaluated_script: Arc<Mutex<Option<String>>>,
+  redraw_count: Arc<AtomicUsize>,
 }
 
 impl MockDispatcher {
   pub fn last_evaluated_script(&self) -> Option<String> {
     self.last_evaluated_script.lock().unwrap().clone()
   }
+
+  pub fn redraw_count(&self) -> usize {
+    self.redraw_count.load(Ordering::Relaxed)
+  }
 }
 
 #[derive(Debug, Clone)]
@@ -518,6 +532,11 @@ impl<T: UserEvent> Dispatch<T> for MockDispatcher {
     Ok(())
   }
 
+  fn request_redraw(&self) -> Result<()> {
+    self.redraw_count.fetch_add(1, Ordering::Relaxed);
+    Ok(())
+  }
+
   fn request_user_attention(&self, request_type: Option<UserAttentionType>) -> Result<()> {
     Ok(())
   }
@@ -534,6 +553,7 @@ impl<T: UserEvent> Dispatch<T> for MockDispatcher {
         id,
         context: self.context.clone(),
         last_evaluated_script: Default::default(),
+        redraw_count: Default::default(),
         url: Arc::new(Mutex::new(pending.url)),
       },
       menu_ids: Default::default(),
@@ -751,6 +771,7 @@ impl MockRuntime {
       windows: Default::default(),
       shortcuts: Default::default(),
       run_tx: tx,
+      is_hidden: Default::default(),
     };
     Self {
       is_running,
@@ -799,6 +820,7 @@ impl<T: UserEvent> Runtime<T> for MockRuntime {
         id,
         context: self.context.clone(),
         last_evaluated_script: Default::default(),
+        redraw_count: Default::default(),
         url: Arc::new(Mutex::new(pending.url)),
       },
       menu_ids: Default::default(),
@@ -821,11 +843,21 @@ impl<T: UserEvent> Runtime<T> for MockRuntime {
 
   #[cfg(target_os = "macos")]
   #[cfg_attr(doc_cfg, doc(cfg(target_os = "macos")))]
-  fn show(&self) {}
+  fn show(&self) {
+    self.context.is_hidden.store(false, Ordering::Relaxed);
+  }
 
   #[cfg(target_os = "macos")]
   #[cfg_attr(doc_cfg, doc(cfg(target_os = "macos")))]
-  fn hide(&self) {}
+  fn hide(&self) {
+    self.context.is_hidden.store(true, Ordering::Relaxed);
+  }
+
+  #[cfg(target_os = "macos")]
+  #[cfg_attr(doc_cfg, doc(cfg(target_os = "macos")))]
+  fn is_hidden(&self) -> bool {
+    self.context.is_hidden.load(Ordering::Relaxed)
+  }
 
   fn set_device_event_filter(&mut self, filter: DeviceEventFilter) {}
 
@@ -56,7 +56,12 @@ use std::{
   fmt,
   hash::{Hash, Hasher},
   path::PathBuf,
-  sync::{Arc, Mutex},
+  sync::{
+    mpsc::{sync_channel, RecvTimeoutError, SyncSender},
+    Arc, Mutex,
+  },
+  thread::JoinHandle,
+  time::{Duration, Instant},
 };
 
 pub(crate) type WebResourceRequestHandler = dyn Fn(&HttpRequest, &mut HttpResponse) + Send + Sync;
@@ -1832,6 +1837,83 @@ impl<R: Runtime> Window<R> {
     self.window.dispatcher.eval_script(js).map_err(Into::into)
   }
 
+  /// Requests an immediate repaint of the webview contents,
+  /// without waiting for a DOM change or a script to run.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **iOS / Android:** Unsupported.
+  pub fn request_redraw(&self) -> crate::Result<()> {
+    self.window.dispatcher.request_redraw().map_err(Into::into)
+  }
+
+  /// Repaints the webview contents every `interval` until the returned [`RepaintHandle`]
+  /// is cancelled or dropped, or the window is destroyed.
+  ///
+  /// Intervals shorter than 4 milliseconds are clamped to avoid flooding the event loop.
+  ///
+  /// # Examples
+  /// ```rust,no_run
+  /// use tauri::Manager;
+  /// use std::time::Duration;
+  ///
+  /// tauri::Builder::default()
+  ///   .setup(|app| {
+  ///     let window = app.get_window("main").unwrap();
+  ///     // repaint at ~60fps for as long as the window lives
+  ///     let handle = window.schedule_repaint(Duration::from_millis(16));
+  ///     app.manage(handle);
+  ///     Ok(())
+  ///   });
+  /// ```
+  pub fn schedule_repaint(&self, interval: Duration) -> RepaintHandle {
+    let interval = interval.max(MIN_REPAINT_INTERVAL);
+    let (cancel_tx, cancel_rx) = sync_channel(1);
+
+    let destroyed_tx = cancel_tx.clone();
+    self.on_window_event(move |event| {
+      if let WindowEvent::Destroyed = event {
+        let _ = destroyed_tx.try_send(());
+      }
+    });
+
+    let window = self.clone();
+    let thread = std::thread::spawn(move || {
+      let mut next_tick = Instant::now();
+      loop {
+        // the event loop ignores messages sent to a destroyed window,
+        // so make sure this window was not closed or replaced by another one with the same label
+        let alive = window
+          .get_window(window.label())
+          .map_or(false, |w| w.is_same_instance(&window));
+        if !alive || window.request_redraw().is_err() {
+          break;
+        }
+
+        next_tick += interval;
+        let now = Instant::now();
+        if next_tick < now {
+          // fell behind, skip the missed ticks instead of redrawing in a burst
+          next_tick = now;
+        }
+        match cancel_rx.recv_timeout(next_tick - now) {
+          Err(RecvTimeoutError::Timeout) => continue,
+          _ => break,
+        }
+      }
+    });
+
+    RepaintHandle {
+      cancel_tx,
+      thread: Some(thread),
+    }
+  }
+
+  /// Whether `other` refers to this window instance, and not just to a window with the same label.
+  fn is_same_instance(&self, other: &Self) -> bool {
+    Arc::ptr_eq(&self.js_event_listeners, &other.js_event_listeners)
+  }
+
   /// Register a JS event listener and return its identifier.
   pub(crate) fn listen_js(
     &self,
@@ -1997,6 +2079,36 @@ impl<R: Runtime> Window<R> {
   }
 }
 
+/// The shortest interval accepted by [`Window::schedule_repaint`].
+const MIN_REPAINT_INTERVAL: Duration = Duration::from_millis(4);
+
+/// A handle to a recurring repaint scheduled with [`Window::schedule_repaint`].
+///
+/// Dropping the handle stops the repaint and waits for the repaint thread to exit.
+#[must_use = "the repaint is cancelled when the handle is dropped"]
+#[derive(Debug)]
+pub struct RepaintHandle {
+  cancel_tx: SyncSender<()>,
+  thread: Option<JoinHandle<()>>,
+}
+
+impl RepaintHandle {
+  /// Stops the recurring repaint.
+  pub fn cancel(&self) {
+    // a full channel means a stop request is already pending
+    let _ = self.cancel_tx.try_send(());
+  }
+}
+
+impl Drop for RepaintHandle {
+  fn drop(&mut self) {
+    self.cancel();
+    if let Some(thread) = self.thread.take() {
+      let _ = thread.join();
+    }
+  }
+}
+
 /// Event system APIs.
 impl<R: Runtime> Window<R> {
   /// Emits an event to both the JavaScript and the Rust listeners.
@@ -2227,9 +2339,75 @@ pub(crate) fn ipc_scope_domain_error_message(url: &str) -> String {
 
 #[cfg(test)]
 mod tests {
+  use std::time::{Duration, Instant};
+
   #[test]
   fn window_is_send_sync() {
     crate::test_utils::assert_send::<super::Window>();
     crate::test_utils::assert_sync::<super::Window>();
   }
+
+  #[test]
+  fn request_redraw() {
+    let app = crate::test::mock_app();
+    let window = crate::WindowBuilder::new(&app, "main", Default::default())
+      .build()
+      .unwrap();
+
+    window.request_redraw().unwrap();
+    assert_eq!(window.window.dispatcher.redraw_count(), 1);
+  }
+
+  fn wait_for_repaint_exit(handle: &super::RepaintHandle) {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while !handle.thread.as_ref().unwrap().is_finished() {
+      assert!(Instant::now() < deadline, "repaint thread did not exit");
+      std::thread::yield_now();
+    }
+  }
+
+  #[test]
+  fn repaint_handle_cancel() {
+    let app = crate::test::mock_app();
+    let window = crate::WindowBuilder::new(&app, "main", Default::default())
+      .build()
+      .unwrap();
+
+    let handle = window.schedule_repaint(Duration::from_secs(60));
+    handle.cancel();
+    // the thread is waiting on a one minute tick, so it only exits this early if it is woken up
+    wait_for_repaint_exit(&handle);
+    assert_eq!(window.window.dispatcher.redraw_count(), 1);
+  }
+
+  #[test]
+  fn repaint_handle_drop() {
+    let app = crate::test::mock_app();
+    let window = crate::WindowBuilder::new(&app, "main", Default::default())
+      .build()
+      .unwrap();
+
+    let handle = window.schedule_repaint(Duration::from_secs(60));
+    // dropping joins the thread, so this would hang if it was not woken up
+    drop(handle);
+    assert_eq!(window.window.dispatcher.redraw_count(), 1);
+  }
+
+  #[test]
+  fn repaint_stops_when_window_is_replaced() {
+    let app = crate::test::mock_app();
+    let window = crate::WindowBuilder::new(&app, "main", Default::default())
+      .build()
+      .unwrap();
+
+    let handle = window.schedule_repaint(Duration::from_millis(10));
+    // simulate the window being destroyed and a new one opening with the same label
+    window.manager.on_window_close(window.label());
+    let new_window = crate::WindowBuilder::new(&app, "main", Default::default())
+      .build()
+      .unwrap();
+
+    wait_for_repaint_exit(&handle);
+    assert_eq!(new_window.window.dispatcher.redraw_count(), 0);
+  }
 }